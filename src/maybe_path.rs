@@ -1,4 +1,6 @@
 use std::{
+    borrow::Cow,
+    cmp::Ordering,
     ffi::OsStr,
     fmt::{Debug, Display},
     hash::Hash,
@@ -153,6 +155,160 @@ impl<'a> MaybePath<'a> {
     pub fn to_owned(&self) -> PathBuf {
         self.as_path().to_path_buf()
     }
+
+    /// Compares against a bare `str`, routed through the same `Path`-normalizing comparison
+    /// `partial_cmp` uses so `==` and ordering never disagree (a raw string comparison would
+    /// treat e.g. `"foo/bar/"` and `"foo/bar"` as unequal, while `Path` normalizes them).
+    pub(crate) fn eq_str(&self, other: &str) -> bool {
+        self.as_path() == Path::new(other)
+    }
+
+    /// Returns an iterator over the `/`-separated components of this path.
+    ///
+    /// For the `str` variant this splits the borrowed `&'a str` on `/` with no allocation,
+    /// collapsing repeated separators and ignoring a trailing empty segment; this keeps
+    /// parsing platform-independent and usable on paths built in a `const` with `new_str`
+    /// (this method itself is not a `const fn`). A leading `/` is
+    /// surfaced as its own `"/"` component first, matching how [`Path::components`] yields
+    /// a root component before the rest of an absolute path. For the `Path` variant this
+    /// defers to [`Path::components`] directly, yielding each component's UTF-8 form;
+    /// components that are not valid UTF-8 are skipped.
+    pub fn components(&self) -> MaybePathComponents<'a> {
+        if self.is_path() {
+            MaybePathComponents::Path(unsafe { self.inner.path }.components())
+        } else {
+            let str = unsafe { self.inner.str };
+            let root = str.starts_with('/').then_some("/");
+            MaybePathComponents::Str {
+                root,
+                split: str.split('/'),
+            }
+        }
+    }
+
+    /// Returns the path without its final component, or `None` if there is none.
+    ///
+    /// For the `str` variant this is a zero-allocation slice of the original string,
+    /// ignoring a trailing separator just like [`Path::parent`] does, and returning `"/"`
+    /// for an absolute path's parent instead of dropping the leading separator; a single
+    /// segment or the root itself yields `None`. For the `Path` variant this defers to
+    /// [`Path::parent`] directly, which yields `None` only for the root (or an empty path)
+    /// and an empty path for a single relative segment.
+    pub fn parent(&self) -> Option<Self> {
+        if self.is_path() {
+            unsafe { self.inner.path }.parent().map(Self::new_path)
+        } else {
+            let str = Self::trim_trailing_sep(unsafe { self.inner.str });
+            if str == "/" {
+                return None;
+            }
+            match str.rfind('/') {
+                Some(0) => Some(Self::new_str("/")),
+                Some(idx) => Some(Self::new_str(&str[..idx])),
+                None => None,
+            }
+        }
+    }
+
+    /// Returns the final component of this path, if there is one.
+    ///
+    /// For the `str` variant this is a zero-allocation slice of the original string; for the
+    /// `Path` variant this defers to [`Path::file_name`].
+    pub fn file_name(&self) -> Option<&'a str> {
+        if self.is_path() {
+            unsafe { self.inner.path }.file_name().and_then(OsStr::to_str)
+        } else {
+            Self::str_file_name(unsafe { self.inner.str })
+        }
+    }
+
+    /// Returns the final component of this path, without its extension, if there is one.
+    ///
+    /// For the `str` variant this is a zero-allocation slice of the original string; for the
+    /// `Path` variant this defers to [`Path::file_stem`].
+    pub fn file_stem(&self) -> Option<&'a str> {
+        if self.is_path() {
+            unsafe { self.inner.path }.file_stem().and_then(OsStr::to_str)
+        } else {
+            let name = Self::str_file_name(unsafe { self.inner.str })?;
+            match name.rfind('.') {
+                Some(0) | None => Some(name),
+                Some(idx) => Some(&name[..idx]),
+            }
+        }
+    }
+
+    /// Returns the extension of the final component of this path, if there is one.
+    ///
+    /// For the `str` variant this is a zero-allocation slice of the original string; for the
+    /// `Path` variant this defers to [`Path::extension`].
+    pub fn extension(&self) -> Option<&'a str> {
+        if self.is_path() {
+            unsafe { self.inner.path }.extension().and_then(OsStr::to_str)
+        } else {
+            let name = Self::str_file_name(unsafe { self.inner.str })?;
+            match name.rfind('.') {
+                Some(0) | None => None,
+                Some(idx) => Some(&name[idx + 1..]),
+            }
+        }
+    }
+
+    /// Returns the final `/`-separated segment of a `str`-backed path, or `None` if it is empty.
+    ///
+    /// A trailing separator is stripped first, so `"foo/bar/"` yields `"bar"` just like
+    /// [`Path::file_name`] does.
+    fn str_file_name(str: &'a str) -> Option<&'a str> {
+        let str = Self::trim_trailing_sep(str);
+        let name = match str.rfind('/') {
+            Some(idx) => &str[idx + 1..],
+            None => str,
+        };
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+
+    /// Strips a single trailing `/`, mirroring how `Path` normalizes a trailing separator
+    /// away before computing `file_name`/`parent`. Leaves `"/"` itself untouched.
+    fn trim_trailing_sep(str: &'a str) -> &'a str {
+        match str.strip_suffix('/') {
+            Some(trimmed) if !trimmed.is_empty() => trimmed,
+            _ => str,
+        }
+    }
+}
+
+/// Iterator over the components of a [`MaybePath`], as returned by [`MaybePath::components`].
+pub enum MaybePathComponents<'a> {
+    /// Iterating over a `str`-backed path. `root` is yielded once, before `split`, for
+    /// absolute paths.
+    Str {
+        /// `Some("/")` if the source path was absolute, consumed after the first `next()`.
+        root: Option<&'a str>,
+        /// The remaining `/`-separated segments.
+        split: std::str::Split<'a, char>,
+    },
+
+    /// Iterating over a `Path`-backed path.
+    Path(std::path::Components<'a>),
+}
+
+impl<'a> Iterator for MaybePathComponents<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Str { root, split } => root
+                .take()
+                .or_else(|| split.find(|segment| !segment.is_empty())),
+            Self::Path(components) => {
+                components.find_map(|component| component.as_os_str().to_str())
+            }
+        }
+    }
 }
 
 impl Default for MaybePath<'_> {
@@ -233,3 +389,128 @@ impl serde::Serialize for MaybePath<'_> {
         self.as_path().serialize(serializer)
     }
 }
+
+impl<'a> From<&'a str> for MaybePath<'a> {
+    /// Routes into the `str` variant, staying const-friendly and avoiding OS re-encoding.
+    #[inline]
+    fn from(str: &'a str) -> Self {
+        Self::new_str(str)
+    }
+}
+
+impl<'a> From<&'a Path> for MaybePath<'a> {
+    #[inline]
+    fn from(path: &'a Path) -> Self {
+        Self::new_path(path)
+    }
+}
+
+impl<'a> From<&'a PathBuf> for MaybePath<'a> {
+    #[inline]
+    fn from(path: &'a PathBuf) -> Self {
+        Self::new_path(path)
+    }
+}
+
+// Transitive `PartialEq`/`PartialOrd` against bare path-like types, following the
+// `transitive_impl` approach used by the `maybe-owned` crate, so a `MaybePath` can be
+// compared directly against a `Path`, `str`, `PathBuf`, etc without wrapping the other side.
+macro_rules! impl_transitive_cmp {
+    ($rhs:ty, |$self:ident, $other:ident| $eq:expr, |$self2:ident, $other2:ident| $ord:expr) => {
+        impl<'a> PartialEq<$rhs> for MaybePath<'a> {
+            #[inline]
+            fn eq(&$self, $other: &$rhs) -> bool {
+                $eq
+            }
+        }
+        impl<'a> PartialEq<MaybePath<'a>> for $rhs {
+            #[inline]
+            fn eq(&self, other: &MaybePath<'a>) -> bool {
+                other == self
+            }
+        }
+        impl<'a> PartialOrd<$rhs> for MaybePath<'a> {
+            #[inline]
+            fn partial_cmp(&$self2, $other2: &$rhs) -> Option<Ordering> {
+                $ord
+            }
+        }
+        impl<'a> PartialOrd<MaybePath<'a>> for $rhs {
+            #[inline]
+            fn partial_cmp(&self, other: &MaybePath<'a>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+impl_transitive_cmp!(
+    Path,
+    |self, other| self.as_path() == other,
+    |self, other| self.as_path().partial_cmp(other)
+);
+impl_transitive_cmp!(
+    PathBuf,
+    |self, other| self.as_path() == other.as_path(),
+    |self, other| self.as_path().partial_cmp(other.as_path())
+);
+impl_transitive_cmp!(
+    str,
+    |self, other| self.eq_str(other),
+    |self, other| self.as_path().partial_cmp(Path::new(other))
+);
+impl_transitive_cmp!(
+    Cow<'_, Path>,
+    |self, other| self.as_path() == &**other,
+    |self, other| self.as_path().partial_cmp(&**other)
+);
+
+impl<'a> PartialEq<&Path> for MaybePath<'a> {
+    #[inline]
+    fn eq(&self, other: &&Path) -> bool {
+        self.as_path() == *other
+    }
+}
+impl<'a> PartialEq<MaybePath<'a>> for &Path {
+    #[inline]
+    fn eq(&self, other: &MaybePath<'a>) -> bool {
+        other == self
+    }
+}
+impl<'a> PartialOrd<&Path> for MaybePath<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &&Path) -> Option<Ordering> {
+        self.as_path().partial_cmp(*other)
+    }
+}
+impl<'a> PartialOrd<MaybePath<'a>> for &Path {
+    #[inline]
+    fn partial_cmp(&self, other: &MaybePath<'a>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl<'a> PartialEq<&str> for MaybePath<'a> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_str(other)
+    }
+}
+impl<'a> PartialEq<MaybePath<'a>> for &str {
+    #[inline]
+    fn eq(&self, other: &MaybePath<'a>) -> bool {
+        other == self
+    }
+}
+impl<'a> PartialOrd<&str> for MaybePath<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.as_path().partial_cmp(Path::new(*other))
+    }
+}
+impl<'a> PartialOrd<MaybePath<'a>> for &str {
+    #[inline]
+    fn partial_cmp(&self, other: &MaybePath<'a>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
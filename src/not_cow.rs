@@ -2,20 +2,111 @@ use crate::MaybePath;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::{Borrow, Cow},
+    cmp::Ordering,
     ffi::OsStr,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
     ops::Deref,
     path::{Path, PathBuf},
 };
 
+/// Interior storage for the owned variant of [`MaybePathBuf`].
+///
+/// Rests as the exactly-sized `Boxed` form whenever possible, since that is two words
+/// instead of a `PathBuf`'s three (and carries no spare capacity to copy on clone). The
+/// rarely-used mutable state is itself boxed (`Box<PathBuf>`, one word) rather than stored
+/// inline, so this enum's own size is driven by the common `Boxed` case instead of matching
+/// whichever variant happens to be larger. [`MaybePathBuf::to_mut`] upgrades to `Buf` lazily,
+/// only when the caller actually needs a growable `PathBuf`, paying one extra allocation at
+/// that point in exchange for the smaller resting size.
+pub enum OwnedPath {
+    /// An exactly-sized heap allocation, as produced by cloning or zero-copy construction.
+    Boxed(Box<Path>),
+
+    /// A growable buffer, as produced once mutable access is requested via `to_mut`.
+    Buf(Box<PathBuf>),
+}
+
+impl OwnedPath {
+    #[inline]
+    fn as_path(&self) -> &Path {
+        match self {
+            Self::Boxed(b) => b,
+            Self::Buf(p) => p,
+        }
+    }
+
+    fn into_path_buf(self) -> PathBuf {
+        match self {
+            Self::Boxed(b) => b.into_path_buf(),
+            Self::Buf(p) => *p,
+        }
+    }
+
+    /// Upgrades to the `Buf` form if necessary, and returns a mutable reference to it.
+    fn to_mut(&mut self) -> &mut PathBuf {
+        if let Self::Boxed(_) = self {
+            let boxed = std::mem::replace(self, Self::Buf(Box::new(PathBuf::new())));
+            if let Self::Boxed(b) = boxed {
+                *self = Self::Buf(Box::new(b.into_path_buf()));
+            }
+        }
+
+        match self {
+            Self::Buf(p) => p,
+            Self::Boxed(_) => unreachable!(),
+        }
+    }
+}
+
+impl Debug for OwnedPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_path(), f)
+    }
+}
+
+impl PartialEq for OwnedPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+impl Eq for OwnedPath {}
+
+impl Ord for OwnedPath {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_path().cmp(other.as_path())
+    }
+}
+impl PartialOrd for OwnedPath {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for OwnedPath {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_path().hash(state);
+    }
+}
+
+impl Serialize for OwnedPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_path().serialize(serializer)
+    }
+}
+
 /// A Near-Zero-Overhead read-only `Path` wrapper that can also hold a `str`, or an owned `PathBuf`.
 ///
-/// The primary usecase is static initialization of a `Path` at compile-time.  
+/// The primary usecase is static initialization of a `Path` at compile-time.
 /// This type is designed to be a drop-in replacement for `Cow<Path>`.
 ///
 /// Acts as a 3-state Cow<Path>:
 /// - `Borrowed(&' Path)`
 /// - `Borrowed(&'a str)`
-/// - `Owned(PathBuf)`
+/// - `Owned(Box<Path>)`
 ///
 /// # Performance
 /// This type has performance matching that of `Cow<Path>`: ( Produced ASM is identical )
@@ -24,13 +115,46 @@ use std::{
 /// - AsRef x1000: `2.1066 µs` vs `3.2081 µs`
 ///
 /// The borrowed variant also stores a `u8` to differentiate between `Path` and `str`,
-#[derive(Debug, Serialize, Eq, PartialEq, PartialOrd, Ord, Hash)]
+/// and the owned variant is backed by an exactly-sized `Box<Path>` rather than a `PathBuf`,
+/// so there is no spare capacity to carry around or pay for on clone. `size_of::<MaybePathBuf>()`
+/// is 24 bytes (matching `PathBuf` itself), down from 32 when the owned variant stored a
+/// `PathBuf` inline.
+#[derive(Debug, Serialize)]
 pub enum MaybePathBuf<'a> {
     /// Borrowed data
     Borrowed(MaybePath<'a>),
 
     /// Owned data
-    Owned(PathBuf),
+    Owned(OwnedPath),
+}
+
+// Hand-rolled instead of derived: a derive compares the `Borrowed`/`Owned` discriminant
+// first, so two instances holding the identical logical path would compare unequal (and
+// hash differently) if one came from a borrow and the other was deserialized/constructed
+// as owned. Comparing through `AsRef<Path>` instead makes equality depend only on the path
+// itself, matching how `MaybePath` already hand-rolls these.
+impl PartialEq for MaybePathBuf<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        AsRef::<Path>::as_ref(self) == AsRef::<Path>::as_ref(other)
+    }
+}
+impl Eq for MaybePathBuf<'_> {}
+
+impl Ord for MaybePathBuf<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        AsRef::<Path>::as_ref(self).cmp(AsRef::<Path>::as_ref(other))
+    }
+}
+impl PartialOrd for MaybePathBuf<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for MaybePathBuf<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        AsRef::<Path>::as_ref(self).hash(state);
+    }
 }
 
 impl Default for MaybePathBuf<'_> {
@@ -40,24 +164,25 @@ impl Default for MaybePathBuf<'_> {
 }
 
 impl Clone for MaybePathBuf<'_> {
+    /// Clones do a single exact-size heap copy: `Box<Path>` has no spare capacity to
+    /// reallocate, unlike `PathBuf`.
     #[inline]
     fn clone(&self) -> Self {
-        match *self {
-            Self::Borrowed(b) => Self::Borrowed(b),
-            Self::Owned(ref o) => {
-                let b = MaybePath::new_path(o);
-                Self::Owned(b.to_owned())
-            }
+        match self {
+            Self::Borrowed(b) => Self::Borrowed(*b),
+            Self::Owned(o) => Self::Owned(OwnedPath::Boxed(Box::from(o.as_path()))),
         }
     }
 
+    /// Reuses the destination's existing growable buffer when it's already in the `Buf`
+    /// form, instead of always reallocating like the default `clone_from` would.
     #[inline]
     fn clone_from(&mut self, source: &Self) {
-        match (self, source) {
-            (&mut Self::Owned(ref mut dest), Self::Owned(o)) => {
-                MaybePath::new_path(o).as_path().clone_into(dest)
-            }
-            (t, s) => *t = s.clone(),
+        if let Self::Owned(OwnedPath::Buf(dst)) = self {
+            dst.as_mut_os_string().clear();
+            dst.push(AsRef::<Path>::as_ref(source));
+        } else {
+            *self = source.clone();
         }
     }
 }
@@ -75,10 +200,32 @@ impl<'a> MaybePathBuf<'a> {
         Self::Borrowed(MaybePath::new_str(s))
     }
 
-    /// Create a new `MaybePathBuf` from a `PathBuf`.  
+    /// Create a new `MaybePathBuf` from a `PathBuf`.
     /// This is the equivalent of `Cow::<Path>::Owned`.
-    pub const fn new_pathbuf(path: PathBuf) -> Self {
-        Self::Owned(path)
+    pub fn new_pathbuf(path: PathBuf) -> Self {
+        Self::Owned(OwnedPath::Boxed(path.into_boxed_path()))
+    }
+
+    /// Create a new `MaybePathBuf` from a `Box<Path>`.
+    /// Like `new_pathbuf`, but takes the exactly-sized allocation as-is instead of shrinking
+    /// a `PathBuf` into one; this is also how `clone` produces its owned data.
+    pub const fn new_boxed(path: Box<Path>) -> Self {
+        Self::Owned(OwnedPath::Boxed(path))
+    }
+
+    /// Create a new `MaybePathBuf` from anything convertible into one, such as a `&str`,
+    /// `&Path`, or owned `PathBuf`, without having to pick a variant by hand.
+    ///
+    /// # Examples
+    /// ```
+    /// use maybe_path::MaybePathBuf;
+    ///
+    /// let from_str = MaybePathBuf::new("foo/bar/baz");
+    /// let from_pathbuf = MaybePathBuf::new(std::path::PathBuf::from("foo/bar/baz"));
+    /// assert_eq!(from_str.as_ref(), from_pathbuf.as_ref());
+    /// ```
+    pub fn new<I: Into<MaybePathBuf<'a>>>(value: I) -> Self {
+        value.into()
     }
 
     /// Returns true if the `MaybePathBuf` is borrowed.
@@ -86,21 +233,22 @@ impl<'a> MaybePathBuf<'a> {
         matches!(self, Self::Borrowed(_))
     }
 
-    /// Acquires a mutable reference to the owned form of the data.  
-    /// If the data is borrowed, it will be cloned.
+    /// Acquires a mutable reference to the owned form of the data.
+    /// If the data is borrowed, it will be cloned. Bridges an exactly-sized `Box<Path>` into
+    /// a growable `PathBuf` on first call; later calls reuse that `PathBuf` directly.
     pub fn to_mut(&mut self) -> &mut PathBuf {
         match self {
             Self::Borrowed(b) => {
                 let b = b.to_owned();
-                *self = Self::Owned(b);
+                *self = Self::Owned(OwnedPath::Buf(Box::new(b)));
 
                 match self {
-                    Self::Owned(ref mut o) => o,
+                    Self::Owned(o) => o.to_mut(),
                     _ => unreachable!(),
                 }
             }
 
-            Self::Owned(ref mut o) => o,
+            Self::Owned(o) => o.to_mut(),
         }
     }
 
@@ -108,7 +256,7 @@ impl<'a> MaybePathBuf<'a> {
     pub fn into_owned(self) -> PathBuf {
         match self {
             Self::Borrowed(b) => b.to_owned(),
-            Self::Owned(o) => o,
+            Self::Owned(o) => o.into_path_buf(),
         }
     }
 
@@ -116,11 +264,64 @@ impl<'a> MaybePathBuf<'a> {
     pub fn into_cow(self) -> Cow<'a, Path> {
         match self {
             Self::Borrowed(b) => Cow::Borrowed(b.as_path()),
-            Self::Owned(o) => Cow::Owned(o),
+            Self::Owned(o) => Cow::Owned(o.into_path_buf()),
+        }
+    }
+
+    /// Compares against a bare `str` via `Path`-normalizing comparison, so this always
+    /// agrees with the `Ord`/`PartialOrd` impls above.
+    pub(crate) fn eq_str(&self, other: &str) -> bool {
+        match self {
+            Self::Borrowed(b) => b.eq_str(other),
+            Self::Owned(o) => o.as_path() == Path::new(other),
         }
     }
 }
 
+impl<'a> From<&'a str> for MaybePathBuf<'a> {
+    /// Routes into the borrowed `str` variant, staying const-friendly and avoiding OS re-encoding.
+    #[inline]
+    fn from(str: &'a str) -> Self {
+        Self::new_str(str)
+    }
+}
+
+impl<'a> From<&'a Path> for MaybePathBuf<'a> {
+    #[inline]
+    fn from(path: &'a Path) -> Self {
+        Self::new_path(path)
+    }
+}
+
+impl<'a> From<&'a PathBuf> for MaybePathBuf<'a> {
+    #[inline]
+    fn from(path: &'a PathBuf) -> Self {
+        Self::new_path(path)
+    }
+}
+
+impl From<PathBuf> for MaybePathBuf<'_> {
+    #[inline]
+    fn from(path: PathBuf) -> Self {
+        Self::new_pathbuf(path)
+    }
+}
+
+impl From<String> for MaybePathBuf<'_> {
+    /// Routes into the owned variant; `String` is already owned, so there is no borrow to keep.
+    #[inline]
+    fn from(str: String) -> Self {
+        Self::new_pathbuf(PathBuf::from(str))
+    }
+}
+
+impl<'a> From<MaybePath<'a>> for MaybePathBuf<'a> {
+    #[inline]
+    fn from(path: MaybePath<'a>) -> Self {
+        Self::Borrowed(path)
+    }
+}
+
 impl<'a> Borrow<Path> for MaybePathBuf<'a> {
     fn borrow(&self) -> &Path {
         self
@@ -147,11 +348,147 @@ impl AsRef<Path> for MaybePathBuf<'_> {
 }
 
 impl<'de> Deserialize<'de> for MaybePathBuf<'de> {
+    /// Deserializes zero-copy when the format can hand back a string borrowed from the
+    /// input (e.g. `serde_json::from_str`), mirroring how `Cow<str>` deserializes. Only
+    /// falls back to an owned path when the format must copy the data.
     fn deserialize<D>(deserializer: D) -> Result<MaybePathBuf<'de>, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let path = PathBuf::deserialize(deserializer)?;
-        Ok(MaybePathBuf::Owned(path))
+        struct MaybePathBufVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for MaybePathBufVisitor {
+            type Value = MaybePathBuf<'de>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a path")
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(MaybePathBuf::Borrowed(MaybePath::new_str(v)))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(MaybePathBuf::new_pathbuf(PathBuf::from(v)))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(MaybePathBuf::new_pathbuf(PathBuf::from(v)))
+            }
+        }
+
+        deserializer.deserialize_str(MaybePathBufVisitor)
+    }
+}
+
+// Transitive `PartialEq`/`PartialOrd` against bare path-like types, following the
+// `transitive_impl` approach used by the `maybe-owned` crate, so a `MaybePathBuf` can be
+// compared directly against a `Path`, `str`, `PathBuf`, etc without wrapping the other side.
+macro_rules! impl_transitive_cmp {
+    ($rhs:ty, |$self:ident, $other:ident| $eq:expr, |$self2:ident, $other2:ident| $ord:expr) => {
+        impl<'a> PartialEq<$rhs> for MaybePathBuf<'a> {
+            #[inline]
+            fn eq(&$self, $other: &$rhs) -> bool {
+                $eq
+            }
+        }
+        impl<'a> PartialEq<MaybePathBuf<'a>> for $rhs {
+            #[inline]
+            fn eq(&self, other: &MaybePathBuf<'a>) -> bool {
+                other == self
+            }
+        }
+        impl<'a> PartialOrd<$rhs> for MaybePathBuf<'a> {
+            #[inline]
+            fn partial_cmp(&$self2, $other2: &$rhs) -> Option<Ordering> {
+                $ord
+            }
+        }
+        impl<'a> PartialOrd<MaybePathBuf<'a>> for $rhs {
+            #[inline]
+            fn partial_cmp(&self, other: &MaybePathBuf<'a>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+impl_transitive_cmp!(
+    Path,
+    |self, other| AsRef::<Path>::as_ref(self) == other,
+    |self, other| AsRef::<Path>::as_ref(self).partial_cmp(other)
+);
+impl_transitive_cmp!(
+    PathBuf,
+    |self, other| AsRef::<Path>::as_ref(self) == other.as_path(),
+    |self, other| AsRef::<Path>::as_ref(self).partial_cmp(other.as_path())
+);
+impl_transitive_cmp!(
+    str,
+    |self, other| self.eq_str(other),
+    |self, other| AsRef::<Path>::as_ref(self).partial_cmp(Path::new(other))
+);
+impl_transitive_cmp!(
+    Cow<'_, Path>,
+    |self, other| AsRef::<Path>::as_ref(self) == &**other,
+    |self, other| AsRef::<Path>::as_ref(self).partial_cmp(&**other)
+);
+
+impl<'a> PartialEq<&Path> for MaybePathBuf<'a> {
+    #[inline]
+    fn eq(&self, other: &&Path) -> bool {
+        AsRef::<Path>::as_ref(self) == *other
+    }
+}
+impl<'a> PartialEq<MaybePathBuf<'a>> for &Path {
+    #[inline]
+    fn eq(&self, other: &MaybePathBuf<'a>) -> bool {
+        other == self
+    }
+}
+impl<'a> PartialOrd<&Path> for MaybePathBuf<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &&Path) -> Option<Ordering> {
+        AsRef::<Path>::as_ref(self).partial_cmp(*other)
+    }
+}
+impl<'a> PartialOrd<MaybePathBuf<'a>> for &Path {
+    #[inline]
+    fn partial_cmp(&self, other: &MaybePathBuf<'a>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl<'a> PartialEq<&str> for MaybePathBuf<'a> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_str(other)
+    }
+}
+impl<'a> PartialEq<MaybePathBuf<'a>> for &str {
+    #[inline]
+    fn eq(&self, other: &MaybePathBuf<'a>) -> bool {
+        other == self
+    }
+}
+impl<'a> PartialOrd<&str> for MaybePathBuf<'a> {
+    #[inline]
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        AsRef::<Path>::as_ref(self).partial_cmp(Path::new(*other))
+    }
+}
+impl<'a> PartialOrd<MaybePathBuf<'a>> for &str {
+    #[inline]
+    fn partial_cmp(&self, other: &MaybePathBuf<'a>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
     }
 }
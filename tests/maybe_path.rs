@@ -1,4 +1,28 @@
 use maybe_path::MaybePath;
+use std::{borrow::Cow, path::{Path, PathBuf}};
+
+#[test]
+fn test_from() {
+    let from_str: MaybePath = "foo/bar/baz".into();
+    let from_path: MaybePath = Path::new("foo/bar/baz").into();
+    let pathbuf = PathBuf::from("foo/bar/baz");
+    let from_pathbuf: MaybePath = (&pathbuf).into();
+
+    assert_eq!(from_str, from_path);
+    assert_eq!(from_str, from_pathbuf);
+}
+
+#[test]
+fn test_transitive_cmp() {
+    let path = MaybePath::new_str("foo/bar/baz");
+
+    assert_eq!(path, "foo/bar/baz");
+    assert_eq!(path, Path::new("foo/bar/baz"));
+    assert_eq!(path, PathBuf::from("foo/bar/baz"));
+    assert_eq!(path, Cow::Borrowed(Path::new("foo/bar/baz")));
+    assert_eq!("foo/bar/baz", path);
+    assert_eq!(Path::new("foo/bar/baz"), path);
+}
 
 #[test]
 fn test_create() {
@@ -19,3 +43,85 @@ fn test_as() {
     assert_eq!(path1.as_str(), path2.as_str());
     assert_eq!(path1.to_owned(), path2.to_owned());
 }
+
+#[test]
+fn test_components_str() {
+    let path = MaybePath::new_str("foo//bar/baz.txt");
+
+    let components: Vec<_> = path.components().collect();
+    assert_eq!(components, ["foo", "bar", "baz.txt"]);
+
+    assert_eq!(path.parent().unwrap(), MaybePath::new_str("foo//bar"));
+    assert_eq!(path.file_name(), Some("baz.txt"));
+    assert_eq!(path.file_stem(), Some("baz"));
+    assert_eq!(path.extension(), Some("txt"));
+
+    let single = MaybePath::new_str("foo");
+    assert_eq!(single.parent(), None);
+}
+
+#[test]
+fn test_components_str_trailing_separator() {
+    let path = MaybePath::new_str("foo/bar/");
+
+    assert_eq!(path.parent().unwrap(), MaybePath::new_str("foo"));
+    assert_eq!(path.file_name(), Some("bar"));
+
+    let with_ext = MaybePath::new_str("foo.txt/");
+    assert_eq!(with_ext.file_stem(), Some("foo"));
+    assert_eq!(with_ext.extension(), Some("txt"));
+
+    let root = MaybePath::new_str("/");
+    assert_eq!(root.file_name(), None);
+}
+
+#[test]
+fn test_components_str_absolute() {
+    let path = MaybePath::new_str("/foo/bar");
+
+    let components: Vec<_> = path.components().collect();
+    assert_eq!(components, ["/", "foo", "bar"]);
+
+    assert_eq!(path.parent().unwrap(), MaybePath::new_str("/foo"));
+
+    let top = MaybePath::new_str("/foo");
+    assert_eq!(top.parent().unwrap(), MaybePath::new_str("/"));
+
+    let root = MaybePath::new_str("/");
+    assert_eq!(root.parent(), None);
+    assert_eq!(root.components().collect::<Vec<_>>(), ["/"]);
+}
+
+#[test]
+fn test_components_path_matches_str() {
+    let as_path = MaybePath::new_path("foo/bar/baz.txt");
+    let as_str = MaybePath::new_str("foo/bar/baz.txt");
+
+    let path_components: Vec<_> = as_path.components().collect();
+    let str_components: Vec<_> = as_str.components().collect();
+    assert_eq!(path_components, str_components);
+
+    assert_eq!(as_path.file_name(), as_str.file_name());
+    assert_eq!(as_path.file_stem(), as_str.file_stem());
+    assert_eq!(as_path.extension(), as_str.extension());
+
+    let as_path = MaybePath::new_path("foo/bar/");
+    let as_str = MaybePath::new_str("foo/bar/");
+
+    assert_eq!(as_path.file_name(), as_str.file_name());
+    assert_eq!(
+        as_path.parent().unwrap().as_path(),
+        as_str.parent().unwrap().as_path()
+    );
+
+    let as_path = MaybePath::new_path("/foo/bar");
+    let as_str = MaybePath::new_str("/foo/bar");
+
+    let path_components: Vec<_> = as_path.components().collect();
+    let str_components: Vec<_> = as_str.components().collect();
+    assert_eq!(path_components, str_components);
+    assert_eq!(
+        as_path.parent().unwrap().as_path(),
+        as_str.parent().unwrap().as_path()
+    );
+}
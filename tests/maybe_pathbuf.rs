@@ -1,4 +1,37 @@
 use maybe_path::MaybePathBuf;
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+#[test]
+fn test_from() {
+    let from_str: MaybePathBuf = "foo/bar/baz".into();
+    let from_path: MaybePathBuf = Path::new("foo/bar/baz").into();
+    let from_pathbuf: MaybePathBuf = PathBuf::from("foo/bar/baz").into();
+    let from_string: MaybePathBuf = String::from("foo/bar/baz").into();
+
+    assert!(from_str.is_borrowed());
+    assert!(from_path.is_borrowed());
+    assert!(!from_pathbuf.is_borrowed());
+    assert!(!from_string.is_borrowed());
+
+    assert_eq!(from_str.as_ref(), from_pathbuf.as_ref());
+    assert_eq!(MaybePathBuf::new(Path::new("foo/bar/baz")), from_path);
+}
+
+#[test]
+fn test_transitive_cmp() {
+    let path = MaybePathBuf::new_str("foo/bar/baz");
+
+    assert_eq!(path, "foo/bar/baz");
+    assert_eq!(path, Path::new("foo/bar/baz"));
+    assert_eq!(path, PathBuf::from("foo/bar/baz"));
+    assert_eq!(path, Cow::Borrowed(Path::new("foo/bar/baz")));
+    assert_eq!("foo/bar/baz", path);
+    assert_eq!(Path::new("foo/bar/baz"), path);
+}
 
 #[test]
 fn test_create() {
@@ -18,3 +51,53 @@ fn test_as() {
     assert_eq!(path1.as_ref(), path2.as_ref());
     assert_eq!(path1.to_owned(), path2.to_owned());
 }
+
+#[test]
+fn test_deserialize_borrows_from_input() {
+    let json = r#""foo/bar/baz""#;
+    let path: MaybePathBuf = serde_json::from_str(json).unwrap();
+
+    assert!(path.is_borrowed());
+    assert_eq!(path, Path::new("foo/bar/baz"));
+}
+
+#[test]
+fn test_deserialize_escaped_string_is_owned() {
+    let json = r#""foo\/bar\/baz""#;
+    let path: MaybePathBuf = serde_json::from_str(json).unwrap();
+
+    assert!(!path.is_borrowed());
+    assert_eq!(path, Path::new("foo/bar/baz"));
+}
+
+#[test]
+fn test_boxed_owned_roundtrip() {
+    let boxed: Box<Path> = Box::from(Path::new("foo/bar/baz"));
+    let path = MaybePathBuf::new_boxed(boxed);
+
+    assert!(!path.is_borrowed());
+    assert_eq!(path, Path::new("foo/bar/baz"));
+    assert_eq!(path.clone(), path);
+    assert_eq!(path.into_owned(), PathBuf::from("foo/bar/baz"));
+}
+
+#[test]
+fn test_to_mut_upgrades_boxed_to_pathbuf() {
+    let mut path = MaybePathBuf::new_boxed(Box::from(Path::new("foo/bar")));
+    path.to_mut().push("baz");
+
+    assert_eq!(path, Path::new("foo/bar/baz"));
+}
+
+#[test]
+fn test_eq_hash_ignore_variant() {
+    let borrowed = MaybePathBuf::new_str("foo/bar");
+    let owned = MaybePathBuf::new_pathbuf(PathBuf::from("foo/bar"));
+
+    assert_eq!(borrowed, owned);
+    assert_eq!(borrowed.cmp(&owned), std::cmp::Ordering::Equal);
+
+    let mut set = HashSet::new();
+    set.insert(borrowed);
+    assert!(set.contains(&owned));
+}